@@ -4,7 +4,11 @@ use crossterm::{
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use freedesktop_entry_parser::parse_entry;
+use std::io::{BufRead, IsTerminal};
 use std::process::{exit, Command, Stdio};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
 use std::{error::Error, fs, io};
 use tui::{
     backend::{Backend, CrosstermBackend},
@@ -19,7 +23,10 @@ use unicode_width::UnicodeWidthStr;
 struct Tmenu {
     input: String,
     app_list: Vec<AppItem>,
+    filtered: Vec<FilteredItem>,
     index: usize,
+    output: Option<CommandOutput>,
+    output_scroll: u16,
 }
 
 #[derive(Debug, Clone)]
@@ -29,22 +36,80 @@ struct AppItem {
     cmd: String,
 }
 
+#[derive(Debug, Clone)]
+struct FilteredItem {
+    item_index: usize,
+    score: i64,
+    matched_indices: Vec<usize>,
+}
+
+// The result of spawning a command in capture mode: either its combined
+// stdout/stderr, or the `io::Error` hit while trying to spawn it.
+#[derive(Debug)]
+struct CommandOutput {
+    contents: Result<String, io::Error>,
+}
+
+// `Desktop` scans `/usr/share/applications` like a regular app launcher.
+// `Stdin` turns tmenu into a dmenu-style filter: entries come from stdin
+// and the chosen line is printed to stdout instead of being spawned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Desktop,
+    Stdin,
+}
+
+// Command-line behavior toggles, resolved once at startup.
+#[derive(Debug, Clone, Copy)]
+struct Config {
+    mode: Mode,
+    // When set, launched commands are spawned with piped output and shown
+    // in a result pane instead of being run with their output discarded
+    // just before tmenu exits.
+    capture: bool,
+}
+
+// Picks `Stdin` mode when `--stdin` is passed explicitly, or when stdin
+// isn't a TTY (e.g. `ls | tmenu`), falling back to the desktop-entry mode.
+fn detect_config() -> Config {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let explicit_stdin = args.iter().any(|arg| arg == "--stdin");
+    let capture = args.iter().any(|arg| arg == "--capture");
+
+    let mode = if explicit_stdin || !io::stdin().is_terminal() {
+        Mode::Stdin
+    } else {
+        Mode::Desktop
+    };
+
+    Config { mode, capture }
+}
+
 impl Tmenu {
     fn default() -> Tmenu {
         Tmenu {
             input: String::new(),
             app_list: Vec::new(),
+            filtered: Vec::new(),
             index: 0,
+            output: None,
+            output_scroll: 0,
         }
     }
     fn next(&mut self) {
-        self.index = (self.index + 1) % self.app_list.len();
+        if self.filtered.is_empty() {
+            return;
+        }
+        self.index = (self.index + 1) % self.filtered.len();
     }
     fn previous(&mut self) {
+        if self.filtered.is_empty() {
+            return;
+        }
         if self.index > 0 {
             self.index -= 1;
         } else {
-            self.index = self.app_list.len() - 1;
+            self.index = self.filtered.len() - 1;
         }
     }
     fn chain_hook(&mut self) {
@@ -55,6 +120,88 @@ impl Tmenu {
             original_hook(panic);
         }));
     }
+    // Recompute `filtered` from `app_list` against the current `input`,
+    // sorted best match first, and clamp `index` back into range.
+    fn refresh_filter(&mut self) {
+        self.filtered = self
+            .app_list
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| {
+                fuzzy_match(&self.input, &item.name)
+                    .map(|(score, matched_indices)| FilteredItem {
+                        item_index: i,
+                        score,
+                        matched_indices,
+                    })
+            })
+            .collect();
+        self.filtered.sort_by(|a, b| b.score.cmp(&a.score));
+
+        if self.filtered.is_empty() {
+            self.index = 0;
+        } else if self.index >= self.filtered.len() {
+            self.index = self.filtered.len() - 1;
+        }
+    }
+    fn selected(&self) -> Option<&AppItem> {
+        self.filtered
+            .get(self.index)
+            .map(|f| &self.app_list[f.item_index])
+    }
+    fn scroll_output_up(&mut self) {
+        self.output_scroll = self.output_scroll.saturating_sub(1);
+    }
+    fn scroll_output_down(&mut self) {
+        self.output_scroll = self.output_scroll.saturating_add(1);
+    }
+}
+
+// Score `candidate` against `query`, matching characters case-insensitively
+// and in order. Returns `None` if not every query character was consumed.
+// Consecutive matches and matches landing on a word boundary (start of
+// string, or right after a space/`-`/`_`) are worth extra.
+fn fuzzy_match(query: &str, candidate: &str) -> Option<(i64, Vec<usize>)> {
+    if query.is_empty() {
+        return Some((0, Vec::new()));
+    }
+
+    let query_lower: Vec<char> = query.to_lowercase().chars().collect();
+    let candidate_chars: Vec<char> = candidate.chars().collect();
+    let candidate_lower: Vec<char> = candidate.to_lowercase().chars().collect();
+
+    let mut matched_indices = Vec::with_capacity(query_lower.len());
+    let mut score: i64 = 0;
+    let mut qi = 0;
+    let mut last_match: Option<usize> = None;
+
+    for (ci, c) in candidate_lower.iter().enumerate() {
+        if qi >= query_lower.len() {
+            break;
+        }
+        if *c != query_lower[qi] {
+            continue;
+        }
+
+        score += 1;
+        if last_match == Some(ci.wrapping_sub(1)) {
+            score += 5;
+        }
+        let at_boundary = ci == 0 || matches!(candidate_chars[ci - 1], ' ' | '-' | '_');
+        if at_boundary {
+            score += 10;
+        }
+
+        matched_indices.push(ci);
+        last_match = Some(ci);
+        qi += 1;
+    }
+
+    if qi == query_lower.len() {
+        Some((score, matched_indices))
+    } else {
+        None
+    }
 }
 
 fn main() -> Result<(), Box<dyn Error>> {
@@ -67,7 +214,8 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     // create app and run it
     let app = Tmenu::default();
-    let res = run_app(&mut terminal, app);
+    let config = detect_config();
+    let res = run_app(&mut terminal, app, config);
 
     // restore terminal
     disable_raw_mode()?;
@@ -92,101 +240,293 @@ fn reset_terminal() -> io::Result<()> {
     Ok(())
 }
 
-fn run_app<B: Backend>(terminal: &mut Terminal<B>, mut app: Tmenu) -> io::Result<()> {
-    app.chain_hook();
+// How often the main loop wakes up even without a keypress, so resizes
+// and future streaming state get redrawn promptly.
+const TICK_RATE: Duration = Duration::from_millis(200);
 
-    for file in fs::read_dir("/usr/share/applications").unwrap() {
-        let file_name = file.unwrap().path().display().to_string();
-        if file_name.ends_with(".desktop") {
-            let entry = parse_entry(file_name)?;
-
-            let name = entry
-                .section("Desktop Entry")
-                .attr("Name")
-                .expect("Name doesn't exist.");
-            let nodsp = entry.section("Desktop Entry").attr("NoDisplay");
-
-            match nodsp {
-                None | Some("false") => {
-                    if let Some(cmd) = entry.section("Desktop Entry").attr("Exec") {
-                        if let Some(generic_name) =
-                            entry.section("Desktop Entry").attr("GenericName")
-                        {
-                            app.app_list.push(AppItem {
-                                name: name.to_string(),
-                                desc: generic_name.to_string(),
-                                cmd: cmd.to_string(),
-                            });
-                        } else {
-                            app.app_list.push(AppItem {
-                                name: name.to_string(),
-                                desc: "".to_string(),
-                                cmd: cmd.to_string(),
-                            });
+enum AppEvent {
+    Input(Event),
+    Tick,
+}
+
+// Spawns a dedicated key-reading thread that forwards crossterm events
+// over a channel, interleaved with a `Tick` on every `tick_rate`. The
+// thread is intentionally never joined so no keypress is swallowed
+// during teardown.
+fn spawn_events(tick_rate: Duration) -> mpsc::Receiver<AppEvent> {
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        let mut last_tick = Instant::now();
+        loop {
+            let timeout = tick_rate.saturating_sub(last_tick.elapsed());
+
+            if event::poll(timeout).unwrap_or(false) {
+                match event::read() {
+                    Ok(ev) => {
+                        if tx.send(AppEvent::Input(ev)).is_err() {
+                            return;
                         }
                     }
+                    Err(_) => return,
                 }
-                _ => {}
+            }
+
+            if last_tick.elapsed() >= tick_rate {
+                if tx.send(AppEvent::Tick).is_err() {
+                    return;
+                }
+                last_tick = Instant::now();
             }
         }
+    });
+
+    rx
+}
+
+fn run_app<B: Backend>(terminal: &mut Terminal<B>, mut app: Tmenu, config: Config) -> io::Result<()> {
+    app.chain_hook();
+
+    match config.mode {
+        Mode::Desktop => load_desktop_entries(&mut app)?,
+        Mode::Stdin => load_stdin_entries(&mut app)?,
     }
 
+    app.refresh_filter();
+
+    let events = spawn_events(TICK_RATE);
+
     loop {
         terminal.draw(|f| ui(f, &app))?;
 
-        if let Event::Key(key) = event::read()? {
+        let event = events
+            .recv()
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+
+        let key = match event {
+            AppEvent::Tick => continue,
+            AppEvent::Input(Event::Key(key)) => key,
+            AppEvent::Input(_) => continue,
+        };
+
+        // While a result pane is open, keys drive that pane instead of
+        // the app list.
+        if app.output.is_some() {
             match key.code {
-                KeyCode::Enter => {
-                    match Command::new("sh")
-                        .arg("-c")
-                        .arg(app.app_list[app.index].cmd.to_string())
-                        .stdin(Stdio::null())
-                        .stdout(Stdio::null())
-                        .stderr(Stdio::null())
-                        .output()
-                    {
-                        Ok(_) => {}
-                        Err(e) => {
-                            println!("Failed to execute command. Error: `{}`", e);
-                        }
+                KeyCode::Esc => app.output = None,
+                KeyCode::Up => app.scroll_output_up(),
+                KeyCode::Down => app.scroll_output_down(),
+                _ => {}
+            }
+            continue;
+        }
+
+        match key.code {
+            KeyCode::Enter => {
+                match (config.mode, app.selected().cloned()) {
+                    (Mode::Stdin, Some(selected)) => {
+                        reset_terminal().unwrap();
+                        println!("{}", selected.name);
+                        exit(0);
                     }
+                    (Mode::Desktop, Some(selected)) if config.capture => {
+                        app.output = Some(capture_command(&selected.cmd));
+                        app.output_scroll = 0;
+                    }
+                    (Mode::Desktop, Some(selected)) => {
+                        match Command::new("sh")
+                            .arg("-c")
+                            .arg(selected.cmd.to_string())
+                            .stdin(Stdio::null())
+                            .stdout(Stdio::null())
+                            .stderr(Stdio::null())
+                            .output()
+                        {
+                            Ok(_) => {}
+                            Err(e) => {
+                                println!("Failed to execute command. Error: `{}`", e);
+                            }
+                        }
 
-                    reset_terminal().unwrap();
-                    exit(0);
-                }
-                KeyCode::Up => {
-                    app.previous();
-                }
-                KeyCode::Down => {
-                    app.next();
-                }
-                KeyCode::Char(c) => {
-                    app.input.push(c);
-                }
-                KeyCode::Backspace => {
-                    app.input.pop();
-                }
-                KeyCode::Esc => {
-                    return Ok(());
+                        reset_terminal().unwrap();
+                        exit(0);
+                    }
+                    (_, None) => {
+                        reset_terminal().unwrap();
+                        exit(0);
+                    }
                 }
-                _ => {}
             }
+            KeyCode::Up => {
+                app.previous();
+            }
+            KeyCode::Down => {
+                app.next();
+            }
+            KeyCode::Char(c) => {
+                app.input.push(c);
+                app.refresh_filter();
+            }
+            KeyCode::Backspace => {
+                app.input.pop();
+                app.refresh_filter();
+            }
+            KeyCode::Esc => {
+                return Ok(());
+            }
+            _ => {}
         }
     }
 }
 
+// Spawns `cmd` with piped stdout/stderr and reads both back into a single
+// buffer, so capture mode can show the launched command's output in a
+// result pane instead of it vanishing into `Stdio::null()`.
+fn capture_command(cmd: &str) -> CommandOutput {
+    let contents = Command::new("sh")
+        .arg("-c")
+        .arg(cmd)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .map(|output| {
+            let mut contents = String::from_utf8_lossy(&output.stdout).into_owned();
+            contents.push_str(&String::from_utf8_lossy(&output.stderr));
+            contents
+        });
+
+    CommandOutput { contents }
+}
+
+// Scans `/usr/share/applications` for `.desktop` entries, the default
+// interactive mode.
+fn load_desktop_entries(app: &mut Tmenu) -> io::Result<()> {
+    for file in fs::read_dir("/usr/share/applications").unwrap() {
+        let file_name = file.unwrap().path().display().to_string();
+        if !file_name.ends_with(".desktop") {
+            continue;
+        }
+
+        let entry = parse_entry(file_name)?;
+        let section = entry.section("Desktop Entry");
+
+        if section.attr("NoDisplay") == Some("true") || section.attr("Hidden") == Some("true") {
+            continue;
+        }
+
+        if let Some(try_exec) = section.attr("TryExec") {
+            if !binary_on_path(try_exec) {
+                continue;
+            }
+        }
+
+        let exec = match section.attr("Exec") {
+            Some(exec) => exec,
+            None => continue,
+        };
+
+        let name = section.attr("Name").expect("Name doesn't exist.");
+        let desc = section.attr("GenericName").unwrap_or("");
+        let terminal = section.attr("Terminal") == Some("true");
+
+        app.app_list.push(AppItem {
+            name: name.to_string(),
+            desc: desc.to_string(),
+            cmd: clean_exec(exec, terminal),
+        });
+    }
+
+    Ok(())
+}
+
+// Checks whether `bin` (a bare name looked up on `PATH`, or a path) refers
+// to a file that exists, used to honor `TryExec` per the Desktop Entry spec.
+fn binary_on_path(bin: &str) -> bool {
+    if bin.contains('/') {
+        return fs::metadata(bin).is_ok();
+    }
+
+    std::env::var_os("PATH")
+        .map(|paths| std::env::split_paths(&paths).any(|dir| dir.join(bin).is_file()))
+        .unwrap_or(false)
+}
+
+// Field codes the Desktop Entry spec allows in `Exec`: file/URL lists
+// (%f %F %u %U), deprecated codes (%d %D %n %N %v %m), and the
+// entry's icon/name/desktop-file codes (%i %c %k). None of these have a
+// meaningful expansion here, so they're dropped; `%%` is unescaped to `%`.
+const EXEC_FIELD_CODES: &[char] = &[
+    'f', 'F', 'u', 'U', 'd', 'D', 'n', 'N', 'v', 'm', 'i', 'c', 'k',
+];
+
+fn strip_field_codes(exec: &str) -> String {
+    let mut result = String::with_capacity(exec.len());
+    let mut chars = exec.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            result.push(c);
+            continue;
+        }
+
+        match chars.peek() {
+            Some('%') => {
+                result.push('%');
+                chars.next();
+            }
+            Some(code) if EXEC_FIELD_CODES.contains(code) => {
+                chars.next();
+            }
+            _ => result.push('%'),
+        }
+    }
+
+    result.trim().to_string()
+}
+
+// Cleans an `Exec` value for handoff to `sh -c`: strips field codes, and
+// when the entry declares `Terminal=true`, wraps the command so it runs
+// inside the user's terminal emulator instead of detaching headlessly.
+fn clean_exec(exec: &str, terminal: bool) -> String {
+    let cleaned = strip_field_codes(exec);
+
+    if terminal {
+        format!("$TERMINAL -e {}", cleaned)
+    } else {
+        cleaned
+    }
+}
+
+// Reads newline-separated lines from stdin into the app list, turning
+// tmenu into a reusable menu filter for shell pipelines (`ls | tmenu`).
+// The chosen line is printed back to stdout rather than spawned.
+fn load_stdin_entries(app: &mut Tmenu) -> io::Result<()> {
+    for line in io::stdin().lock().lines() {
+        app.app_list.push(AppItem {
+            name: line?,
+            desc: String::new(),
+            cmd: String::new(),
+        });
+    }
+
+    Ok(())
+}
+
 fn ui<B: Backend>(f: &mut Frame<B>, app: &Tmenu) {
+    let mut constraints = vec![
+        Constraint::Length(1),
+        Constraint::Length(3),
+        Constraint::Min(1),
+    ];
+    if app.output.is_some() {
+        constraints.push(Constraint::Min(3));
+    }
+
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .margin(2)
-        .constraints(
-            [
-                Constraint::Length(1),
-                Constraint::Length(3),
-                Constraint::Min(1),
-            ]
-            .as_ref(),
-        )
+        .constraints(constraints)
         .split(f.size());
 
     let (msg, style) = (
@@ -220,18 +560,34 @@ fn ui<B: Backend>(f: &mut Frame<B>, app: &Tmenu) {
     f.set_cursor(chunks[1].x + app.input.width() as u16 + 1, chunks[1].y + 1);
 
     let app_list: Vec<ListItem> = app
-        .app_list
+        .filtered
         .iter()
-        .enumerate()
-        .map(|(_i, m)| {
-            let mut display_str = String::new();
-            if m.desc == "" {
-                display_str.push_str(&format!("{}", m.name));
-            } else {
-                display_str.push_str(&format!("{} [{}]", m.name, m.desc));
+        .map(|f| {
+            let m = &app.app_list[f.item_index];
+
+            let mut spans: Vec<Span> = m
+                .name
+                .chars()
+                .enumerate()
+                .map(|(i, c)| {
+                    if f.matched_indices.contains(&i) {
+                        Span::styled(
+                            c.to_string(),
+                            Style::default()
+                                .add_modifier(Modifier::BOLD)
+                                .fg(Color::Yellow),
+                        )
+                    } else {
+                        Span::raw(c.to_string())
+                    }
+                })
+                .collect();
+
+            if !m.desc.is_empty() {
+                spans.push(Span::raw(format!(" [{}]", m.desc)));
             }
-            let content = vec![Spans::from(Span::raw(display_str))];
-            ListItem::new(content)
+
+            ListItem::new(vec![Spans::from(spans)])
         })
         .collect();
 
@@ -249,4 +605,15 @@ fn ui<B: Backend>(f: &mut Frame<B>, app: &Tmenu) {
         )
         .highlight_symbol("> ");
     f.render_stateful_widget(list, chunks[2], &mut state);
+
+    if let Some(output) = &app.output {
+        let (title, text) = match &output.contents {
+            Ok(contents) => ("Output (Esc to go back)", contents.clone()),
+            Err(e) => ("Failed to spawn (Esc to go back)", e.to_string()),
+        };
+        let output_pane = Paragraph::new(text)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .scroll((app.output_scroll, 0));
+        f.render_widget(output_pane, chunks[3]);
+    }
 }